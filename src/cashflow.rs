@@ -0,0 +1,148 @@
+//! `CashFlowStream` wraps a signed `Vec<f64>` of per-period cash flows and
+//! sums the NPV series directly; `internal_rate_of_return` roots that sum
+//! with Newton-Raphson, falling back to bisection on a sign-changing bracket
+//! when the derivative is too flat or the iteration doesn't converge.
+
+use crate::ValueError;
+
+/// A sequence of signed cash flows indexed by period (index 0 is "now").
+/// Outlays are negative, inflows are positive, e.g. `[-1000.0, 300.0, 300.0,
+/// 300.0, 300.0]` for an initial investment followed by four returns.
+pub struct CashFlowStream {
+    flows: Vec<f64>,
+}
+
+impl CashFlowStream {
+    pub fn new(flows: Vec<f64>) -> Self {
+        Self { flows }
+    }
+
+    pub fn flows(&self) -> &[f64] {
+        &self.flows
+    }
+
+    /// NPV(rate) = sum over t of flows\[t\] / (1 + rate)^t.
+    pub fn net_present_value(&self, rate: f64) -> f64 {
+        self.flows
+            .iter()
+            .enumerate()
+            .map(|(t, cf)| cf / (1.0 + rate).powi(t as i32))
+            .sum()
+    }
+
+    /// NPV'(rate) = sum over t of -t * flows\[t\] / (1 + rate)^(t + 1).
+    fn npv_derivative(&self, rate: f64) -> f64 {
+        self.flows
+            .iter()
+            .enumerate()
+            .map(|(t, cf)| -(t as f64) * cf / (1.0 + rate).powi(t as i32 + 1))
+            .sum()
+    }
+
+    /// Solves NPV(r) = 0 via Newton-Raphson starting from a 10% guess,
+    /// falling back to bisection on a sign-changing bracket if the
+    /// derivative vanishes or the iteration fails to converge.
+    pub fn internal_rate_of_return(&self) -> Result<f64, ValueError> {
+        if self.flows.is_empty() {
+            return Err(ValueError::EmptyCashFlow);
+        }
+
+        const MAX_ITERATIONS: u32 = 100;
+        const TOLERANCE: f64 = 1e-7;
+
+        let mut rate = 0.1;
+        for _ in 0..MAX_ITERATIONS {
+            let npv = self.net_present_value(rate);
+            if npv.abs() < TOLERANCE {
+                return Ok(rate);
+            }
+
+            let derivative = self.npv_derivative(rate);
+            if derivative.abs() < TOLERANCE {
+                break;
+            }
+
+            rate -= npv / derivative;
+        }
+
+        self.bisect_root().ok_or(ValueError::EmptyCashFlow)
+    }
+
+    /// Scans `[-0.99, 10.0]` for a pair of adjacent samples where NPV changes
+    /// sign, then bisects within that bracket. Returns `None` if no such
+    /// bracket exists.
+    fn bisect_root(&self) -> Option<f64> {
+        const SCAN_STEPS: u32 = 200;
+        const BISECT_ITERATIONS: u32 = 100;
+        const TOLERANCE: f64 = 1e-7;
+        const LO_BOUND: f64 = -0.99;
+        const HI_BOUND: f64 = 10.0;
+
+        let step = (HI_BOUND - LO_BOUND) / SCAN_STEPS as f64;
+        let mut prev_rate = LO_BOUND;
+        let mut prev_npv = self.net_present_value(prev_rate);
+
+        for i in 1..=SCAN_STEPS {
+            let rate = LO_BOUND + step * i as f64;
+            let npv = self.net_present_value(rate);
+
+            if prev_npv.signum() != npv.signum() {
+                let (mut lo, mut hi) = (prev_rate, rate);
+                let mut lo_npv = prev_npv;
+
+                for _ in 0..BISECT_ITERATIONS {
+                    let mid = (lo + hi) / 2.0;
+                    let mid_npv = self.net_present_value(mid);
+                    if mid_npv.abs() < TOLERANCE {
+                        return Some(mid);
+                    }
+                    if lo_npv.signum() == mid_npv.signum() {
+                        lo = mid;
+                        lo_npv = mid_npv;
+                    } else {
+                        hi = mid;
+                    }
+                }
+
+                return Some((lo + hi) / 2.0);
+            }
+
+            prev_rate = rate;
+            prev_npv = npv;
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn npv_of_level_flows_matches_manual_sum() {
+        let stream = CashFlowStream::new(vec![-1000.0, 300.0, 300.0, 300.0, 300.0]);
+        let expected: f64 = [-1000.0, 300.0, 300.0, 300.0, 300.0]
+            .iter()
+            .enumerate()
+            .map(|(t, cf)| cf / 1.1f64.powi(t as i32))
+            .sum();
+        assert!((stream.net_present_value(0.1) - expected).abs() < 1e-9);
+    }
+
+    #[test]
+    fn irr_of_simple_project_is_root_of_npv() {
+        let stream = CashFlowStream::new(vec![-1000.0, 300.0, 300.0, 300.0, 300.0, 300.0]);
+        let irr = stream.internal_rate_of_return().unwrap();
+        assert!(stream.net_present_value(irr).abs() < 1e-6);
+    }
+
+    #[test]
+    fn irr_of_empty_stream_errors() {
+        let stream = CashFlowStream::new(vec![]);
+        assert_eq!(
+            stream.internal_rate_of_return().unwrap_err(),
+            ValueError::EmptyCashFlow
+        );
+    }
+}
@@ -2,39 +2,183 @@
 #![deny(rust_2018_idioms)]
 
 use core::f64;
+use std::fmt;
+use std::iter::Sum;
 use std::marker::PhantomData;
+use std::ops::{Add, RangeInclusive, Sub};
+
+mod cashflow;
+pub use cashflow::CashFlowStream;
+
+mod loan;
+pub use loan::{AmortizationRow, Loan};
+
+mod rate;
+pub use rate::{Compounding, Rate};
+
+mod perpetuity;
+pub use perpetuity::{GrowingAnnuity, GrowingPerpetuity, Perpetuity};
+
+mod schedule;
+pub use schedule::{AsRows, ScheduleRow};
 
 pub trait TimeValue {
     fn present_value(&self) -> Result<f64, ValueError>;
     fn future_value(&self) -> Result<f64, ValueError>;
 }
 
-fn round(value: f64) -> f64 {
+/// Rounds `value` to two decimal places, then rejects it as `Err(ValueError::OutOfRange)`
+/// if it's non-finite or falls outside the target `Constraint`. The range
+/// check happens on the `f64` itself, before any cast to `i64`: that cast
+/// saturates rather than erroring (`1e73 as i64 == i64::MAX`, `-0.4 as i64
+/// == 0`), so checking the already-cast integer would wrongly accept
+/// out-of-range floats that happen to saturate into range.
+pub(crate) fn checked_amount<C: Constraint>(value: f64) -> Result<f64, ValueError> {
+    let value = round(value);
+    if !value.is_finite() {
+        return Err(ValueError::OutOfRange);
+    }
+    if value < *C::RANGE.start() as f64 || value > *C::RANGE.end() as f64 {
+        return Err(ValueError::OutOfRange);
+    }
+    Ok(value)
+}
+
+pub(crate) fn round(value: f64) -> f64 {
     (value * 100.0).round() / 100.0
 }
 
-pub struct SingleSum<T>
-where
-    T: Into<f64> + Copy,
-{
-    amount: T,
+/// Bounds the set of integer minor-unit values a [`Money`] is allowed to hold.
+///
+/// Implementors are zero-sized marker types; only `RANGE` matters.
+pub trait Constraint {
+    const RANGE: RangeInclusive<i64>;
+}
+
+/// Rejects negative amounts. Used for principals, cash prices, and anything
+/// that cannot sensibly go below zero.
+pub struct NonNegative;
+
+impl Constraint for NonNegative {
+    const RANGE: RangeInclusive<i64> = 0..=i64::MAX;
+}
+
+/// Accepts any `i64` value, including negative ones. Used for net cash flows
+/// where an outlay is represented as a negative amount.
+pub struct SignedAllowed;
+
+impl Constraint for SignedAllowed {
+    const RANGE: RangeInclusive<i64> = i64::MIN..=i64::MAX;
+}
+
+/// A monetary amount stored as an `i64`, range-checked against a
+/// [`Constraint`] at construction time. The unit (major currency units,
+/// cents, or any other fixed denomination) is up to the caller; `Money`
+/// itself is unit-agnostic and never rescales.
+///
+/// Storing the amount as an integer instead of threading raw `f64` through
+/// the crate removes an entire class of NaN/overflow/precision bugs: a
+/// `Money` value is either valid for its `Constraint` or it doesn't exist.
+pub struct Money<C: Constraint> {
+    minor_units: i64,
+    _marker: PhantomData<C>,
+}
+
+impl<C: Constraint> Money<C> {
+    /// Builds a `Money` from a raw minor-unit value, failing if it falls
+    /// outside `C::RANGE`.
+    pub fn try_from_i64(value: i64) -> Result<Self, ValueError> {
+        if !C::RANGE.contains(&value) {
+            return Err(ValueError::OutOfRange);
+        }
+        Ok(Self {
+            minor_units: value,
+            _marker: PhantomData,
+        })
+    }
+
+    pub fn minor_units(&self) -> i64 {
+        self.minor_units
+    }
+
+    /// Re-checks this amount against a different `Constraint`, e.g. to widen
+    /// a `NonNegative` result into a `SignedAllowed` one, or to narrow back
+    /// down after confirming it can't be negative.
+    pub fn constrain<C2: Constraint>(self) -> Result<Money<C2>, ValueError> {
+        Money::<C2>::try_from_i64(self.minor_units)
+    }
+}
+
+impl<C: Constraint> Clone for Money<C> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<C: Constraint> Copy for Money<C> {}
+
+impl<C: Constraint> PartialEq for Money<C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.minor_units == other.minor_units
+    }
+}
+
+impl<C: Constraint> Eq for Money<C> {}
+
+impl<C: Constraint> fmt::Debug for Money<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Money").field(&self.minor_units).finish()
+    }
+}
+
+impl<C: Constraint> Add for Money<C> {
+    type Output = Result<Money<C>, ValueError>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        let sum = self
+            .minor_units
+            .checked_add(rhs.minor_units)
+            .ok_or(ValueError::OutOfRange)?;
+        Money::try_from_i64(sum)
+    }
+}
+
+impl<C: Constraint> Sub for Money<C> {
+    type Output = Result<Money<C>, ValueError>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        let diff = self
+            .minor_units
+            .checked_sub(rhs.minor_units)
+            .ok_or(ValueError::OutOfRange)?;
+        Money::try_from_i64(diff)
+    }
+}
+
+impl<C: Constraint> Sum<Money<C>> for Result<Money<C>, ValueError> {
+    fn sum<I: Iterator<Item = Money<C>>>(mut iter: I) -> Self {
+        iter.try_fold(Money::<C>::try_from_i64(0)?, |acc, m| acc + m)
+    }
+}
+
+pub struct SingleSum<C: Constraint> {
+    amount: Money<C>,
     rate: f64,
     period: u32,
+    compounding: Compounding,
 }
 
-impl<T> SingleSum<T>
-where
-    T: Into<f64> + Copy,
-{
-    fn new(amt: T, rate: f64, period: u32) -> Self {
+impl<C: Constraint> SingleSum<C> {
+    fn new(amount: Money<C>, rate: f64, period: u32, compounding: Compounding) -> Self {
         Self {
-            amount: amt,
+            amount,
             rate,
             period,
+            compounding,
         }
     }
 
-    pub fn amount(&self) -> T {
+    pub fn amount(&self) -> Money<C> {
         self.amount
     }
 
@@ -46,8 +190,12 @@ where
         self.period
     }
 
-    pub fn set_amount(&mut self, amt: T) {
-        self.amount = amt;
+    pub fn compounding(&self) -> Compounding {
+        self.compounding
+    }
+
+    pub fn set_amount(&mut self, amount: Money<C>) {
+        self.amount = amount;
     }
 
     pub fn set_rate(&mut self, rate: f64) {
@@ -57,19 +205,21 @@ where
     pub fn set_period(&mut self, period: u32) {
         self.period = period;
     }
+
+    pub fn set_compounding(&mut self, compounding: Compounding) {
+        self.compounding = compounding;
+    }
 }
 
-impl<T> TimeValue for SingleSum<T>
-where
-    T: Into<f64> + Copy,
-{
+impl<C: Constraint> TimeValue for SingleSum<C> {
     fn present_value(&self) -> Result<f64, ValueError> {
         if self.rate < 0.0 {
             return Err(ValueError::NegativeDiscount);
         }
 
-        let pv = self.amount.into() / (1.0 + self.rate).powi(self.period as i32);
-        Ok(round(pv))
+        let pv = self.amount.minor_units() as f64
+            / self.compounding.growth_factor(self.rate, self.period);
+        checked_amount::<C>(pv)
     }
 
     fn future_value(&self) -> Result<f64, ValueError> {
@@ -77,50 +227,70 @@ where
             return Err(ValueError::NegativeDiscount);
         }
 
-        let fv = self.amount.into() * (1.0 + self.rate).powi(self.period as i32);
-        Ok(round(fv))
+        let fv = self.amount.minor_units() as f64
+            * self.compounding.growth_factor(self.rate, self.period);
+        checked_amount::<C>(fv)
     }
 }
 
-pub struct Annuity<T, M>
+pub struct Annuity<C, M>
 where
-    T: Into<f64> + Copy,
+    C: Constraint,
 {
-    cashflow: T,
+    cashflow: Money<C>,
     rate: f64,
     period: u32,
+    compounding: Compounding,
     _marker: PhantomData<M>,
 }
 
-impl<T, M> Annuity<T, M>
+impl<C, M> Annuity<C, M>
 where
-    T: Into<f64> + Copy,
+    C: Constraint,
 {
-    pub fn new(cashflows: T, rate: f64, period: u32) -> Self {
+    pub fn new(cashflow: Money<C>, rate: f64, period: u32, compounding: Compounding) -> Self {
         Self {
-            cashflow: cashflows,
+            cashflow,
             rate,
             period,
+            compounding,
             _marker: PhantomData,
         }
     }
+
+    pub fn cashflow(&self) -> Money<C> {
+        self.cashflow
+    }
+
+    pub fn rate(&self) -> f64 {
+        self.rate
+    }
+
+    pub fn period(&self) -> u32 {
+        self.period
+    }
+
+    pub fn compounding(&self) -> Compounding {
+        self.compounding
+    }
 }
 
 pub struct Regular {}
 pub struct Due {}
 
-impl<T> TimeValue for Annuity<T, Due>
+impl<C> TimeValue for Annuity<C, Due>
 where
-    T: Into<f64> + Copy,
+    C: Constraint,
 {
     fn present_value(&self) -> Result<f64, ValueError> {
         if self.rate < 0.0 {
             return Err(ValueError::NegativeDiscount);
         }
-        let pv_r: Annuity<T, Regular> = Annuity::new(self.cashflow, self.rate, self.period);
-        let pv = pv_r.present_value().unwrap();
-        let res = pv * (1.0 + self.rate);
-        Ok(round(res))
+        let pv_r: Annuity<C, Regular> =
+            Annuity::new(self.cashflow, self.rate, self.period, self.compounding);
+        let pv = pv_r.present_value()?;
+        let per_period = self.compounding.growth_factor(self.rate, 1);
+        checked_amount::<C>(pv * per_period)
     }
 
     fn future_value(&self) -> Result<f64, ValueError> {
@@ -128,7 +298,7 @@ where
             return Err(ValueError::NegativeDiscount);
         }
         let factor = |rate: f64, period: u32| -> f64 {
-            let f = (1.0 + rate);
+            let f = self.compounding.growth_factor(rate, 1);
             let mut res = f;
             let mut result = Vec::new();
             let count = 10;
@@ -141,15 +311,14 @@ where
         };
 
         let f = factor(self.rate, self.period);
-        let pv = self.cashflow.into() * f;
-
-        Ok(round(pv))
+        let pv = self.cashflow.minor_units() as f64 * f;
+        checked_amount::<C>(pv)
     }
 }
 
-impl<T> TimeValue for Annuity<T, Regular>
+impl<C> TimeValue for Annuity<C, Regular>
 where
-    T: Into<f64> + Copy,
+    C: Constraint,
 {
     fn present_value(&self) -> Result<f64, ValueError> {
         if self.rate < 0.0 {
@@ -157,7 +326,7 @@ where
         }
 
         let factor = |rate: f64, period: u32| -> f64 {
-            let f = 1.0 / (1.0 + rate);
+            let f = 1.0 / self.compounding.growth_factor(rate, 1);
             let mut res = f;
             let mut result = Vec::new();
             let count = 10;
@@ -170,9 +339,8 @@ where
         };
 
         let f = factor(self.rate, self.period);
-        let pv = self.cashflow.into() * f;
-
-        Ok(round(pv))
+        let pv = self.cashflow.minor_units() as f64 * f;
+        checked_amount::<C>(pv)
     }
 
     fn future_value(&self) -> Result<f64, ValueError> {
@@ -181,7 +349,7 @@ where
         }
 
         let factor = |rate: f64, period: u32| -> f64 {
-            let f = (1.0 + rate);
+            let f = self.compounding.growth_factor(rate, 1);
             let mut res = f;
             let mut result = Vec::new();
             let count = 10;
@@ -194,9 +362,8 @@ where
         };
 
         let f = factor(self.rate, self.period) + 1.0;
-        let pv = self.cashflow.into() * f;
-
-        Ok(round(pv))
+        let pv = self.cashflow.minor_units() as f64 * f;
+        checked_amount::<C>(pv)
     }
 }
 
@@ -204,19 +371,17 @@ where
 pub enum ValueError {
     NegativeDiscount,
     EmptyCashFlow,
+    OutOfRange,
+    RateBelowGrowth,
 }
 
+#[derive(Default)]
 pub enum AnnuityKind {
+    #[default]
     Regular,
     Due,
 }
 
-impl Default for AnnuityKind {
-    fn default() -> Self {
-        Self::Regular
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use std::fmt::Result;
@@ -225,41 +390,123 @@ mod tests {
 
     #[test]
     fn single_sum_fv() {
-        let single_sum = SingleSum::new(150_000, 0.12, 10);
+        let single_sum = SingleSum::new(
+            Money::<NonNegative>::try_from_i64(150_000).unwrap(),
+            0.12,
+            10,
+            Compounding::PerYear(1),
+        );
         let fv = single_sum.future_value().unwrap();
         assert_eq!(fv, 465877.23);
     }
 
     #[test]
     fn single_sum_pv() {
-        let f = SingleSum::new(1_000, 0.10, 3);
+        let f = SingleSum::new(
+            Money::<NonNegative>::try_from_i64(1_000).unwrap(),
+            0.10,
+            3,
+            Compounding::PerYear(1),
+        );
         let pv = f.present_value().unwrap();
         assert_eq!(pv, 751.31);
     }
     #[test]
     fn annuity_pv() {
-        let f: Annuity<i32, Regular> = Annuity::new(5_000, 0.12, 10);
+        let f: Annuity<NonNegative, Regular> =
+            Annuity::new(Money::try_from_i64(5_000).unwrap(), 0.12, 10, Compounding::PerYear(1));
         let pv = f.present_value().unwrap();
         assert_eq!(pv, 28_251.12);
     }
     #[test]
     fn annuity_pv_due() {
-        let f: Annuity<i32, Due> = Annuity::new(5_000, 0.12, 10);
+        let f: Annuity<NonNegative, Due> =
+            Annuity::new(Money::try_from_i64(5_000).unwrap(), 0.12, 10, Compounding::PerYear(1));
         let pv = f.present_value().unwrap();
         assert_eq!(pv, 31641.25);
     }
 
     #[test]
     fn annuity_fv_reg() {
-        let f: Annuity<i32, Regular> = Annuity::new(50_000, 0.09, 7);
+        let f: Annuity<NonNegative, Regular> =
+            Annuity::new(Money::try_from_i64(50_000).unwrap(), 0.09, 7, Compounding::PerYear(1));
         let pv = f.future_value().unwrap();
         assert_eq!(pv, 460021.73);
     }
-    
+
     #[test]
     fn annuity_fv_due() {
-        let f: Annuity<i32, Due> = Annuity::new(200_000, 0.12, 7);
+        let f: Annuity<NonNegative, Due> =
+            Annuity::new(Money::try_from_i64(200_000).unwrap(), 0.12, 7, Compounding::PerYear(1));
         let pv = f.future_value().unwrap();
         assert_eq!(pv, 2_259_938.63);
     }
+
+    #[test]
+    fn money_out_of_range() {
+        let err = Money::<NonNegative>::try_from_i64(-1).unwrap_err();
+        assert_eq!(err, ValueError::OutOfRange);
+    }
+
+    #[test]
+    fn money_checked_add() {
+        let a = Money::<NonNegative>::try_from_i64(10).unwrap();
+        let b = Money::<NonNegative>::try_from_i64(5).unwrap();
+        let sum = (a + b).unwrap();
+        assert_eq!(sum.minor_units(), 15);
+    }
+
+    #[test]
+    fn money_checked_sub_out_of_range() {
+        let a = Money::<NonNegative>::try_from_i64(5).unwrap();
+        let b = Money::<NonNegative>::try_from_i64(10).unwrap();
+        assert_eq!((a - b).unwrap_err(), ValueError::OutOfRange);
+    }
+
+    #[test]
+    fn money_sum() {
+        let amounts = [1, 2, 3].map(|v| Money::<NonNegative>::try_from_i64(v).unwrap());
+        let total: core::result::Result<Money<NonNegative>, ValueError> = amounts.into_iter().sum();
+        assert_eq!(total.unwrap().minor_units(), 6);
+    }
+
+    #[test]
+    fn future_value_overflow_is_an_error_not_infinity() {
+        let single_sum = SingleSum::new(
+            Money::<NonNegative>::try_from_i64(1_000).unwrap(),
+            5.0,
+            100_000,
+            Compounding::PerYear(1),
+        );
+        assert_eq!(
+            single_sum.future_value().unwrap_err(),
+            ValueError::OutOfRange
+        );
+    }
+
+    #[test]
+    fn future_value_finite_but_huge_is_an_error_not_a_saturated_cast() {
+        // 1 * 11^70 is finite but far beyond i64::MAX; a bare `as i64` cast
+        // would saturate to i64::MAX, which is inside NonNegative's range.
+        let single_sum = SingleSum::new(
+            Money::<NonNegative>::try_from_i64(1).unwrap(),
+            10.0,
+            70,
+            Compounding::PerYear(1),
+        );
+        assert_eq!(
+            single_sum.future_value().unwrap_err(),
+            ValueError::OutOfRange
+        );
+    }
+
+    #[test]
+    fn checked_amount_rejects_negative_truncating_to_zero_for_non_negative() {
+        // -0.4 truncates toward zero to 0i64 on cast, which is inside
+        // NonNegative's 0..=i64::MAX; the range check must catch it first.
+        assert_eq!(
+            checked_amount::<NonNegative>(-0.4).unwrap_err(),
+            ValueError::OutOfRange
+        );
+    }
 }
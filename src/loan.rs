@@ -0,0 +1,174 @@
+//! `Loan::payment` computes the constant per-period payment for a principal
+//! repaid over a fixed number of periods, and `Loan::schedule` walks it
+//! forward period by period into a `Vec<AmortizationRow>` carrying the
+//! interest/principal split and the running balance.
+
+use crate::{round, ValueError};
+
+/// One row of an amortization schedule.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AmortizationRow {
+    pub period: u32,
+    pub payment: f64,
+    pub interest: f64,
+    pub principal: f64,
+    pub balance: f64,
+}
+
+/// A level-payment loan: a principal repaid over `periods` installments at a
+/// fixed periodic `rate`.
+pub struct Loan {
+    principal: f64,
+    rate: f64,
+    periods: u32,
+}
+
+impl Loan {
+    pub fn new(principal: f64, rate: f64, periods: u32) -> Self {
+        Self {
+            principal,
+            rate,
+            periods,
+        }
+    }
+
+    fn validate(&self) -> Result<(), ValueError> {
+        if !self.principal.is_finite() || self.principal < 0.0 {
+            return Err(ValueError::OutOfRange);
+        }
+        if self.rate < 0.0 {
+            return Err(ValueError::NegativeDiscount);
+        }
+        if self.periods == 0 {
+            return Err(ValueError::EmptyCashFlow);
+        }
+        Ok(())
+    }
+
+    /// The level payment A = P*r*(1+r)^n / ((1+r)^n - 1), or P/n when r = 0.
+    pub fn payment(&self) -> Result<f64, ValueError> {
+        self.validate()?;
+
+        if self.rate == 0.0 {
+            return Ok(round(self.principal / self.periods as f64));
+        }
+
+        let factor = (1.0 + self.rate).powi(self.periods as i32);
+        Ok(round(self.principal * self.rate * factor / (factor - 1.0)))
+    }
+
+    /// The full amortization schedule. The final row's balance is forced to
+    /// exactly zero to absorb rounding drift from the earlier rows.
+    pub fn schedule(&self) -> Result<Vec<AmortizationRow>, ValueError> {
+        let payment = self.payment()?;
+        let mut balance = self.principal;
+        let mut rows = Vec::with_capacity(self.periods as usize);
+
+        for period in 1..=self.periods {
+            let interest = round(balance * self.rate);
+            let mut principal_paid = round(payment - interest);
+            balance = round(balance - principal_paid);
+
+            if period == self.periods {
+                principal_paid = round(principal_paid + balance);
+                balance = 0.0;
+            }
+
+            rows.push(AmortizationRow {
+                period,
+                payment,
+                interest,
+                principal: principal_paid,
+                balance,
+            });
+        }
+
+        Ok(rows)
+    }
+
+    pub fn total_interest_paid(&self) -> Result<f64, ValueError> {
+        Ok(round(self.schedule()?.iter().map(|row| row.interest).sum()))
+    }
+
+    /// The outstanding balance after `periods_elapsed` payments have been
+    /// made, or the full principal if none have.
+    pub fn remaining_balance_after(&self, periods_elapsed: u32) -> Result<f64, ValueError> {
+        self.validate()?;
+
+        if periods_elapsed == 0 {
+            return Ok(self.principal);
+        }
+
+        Ok(self
+            .schedule()?
+            .get(periods_elapsed as usize - 1)
+            .map(|row| row.balance)
+            .unwrap_or(0.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn schedule_final_balance_is_exactly_zero() {
+        let loan = Loan::new(150_000.0, 0.005, 24);
+        let schedule = loan.schedule().unwrap();
+        assert_eq!(schedule.last().unwrap().balance, 0.0);
+    }
+
+    #[test]
+    fn schedule_has_one_row_per_period() {
+        let loan = Loan::new(10_000.0, 0.01, 12);
+        assert_eq!(loan.schedule().unwrap().len(), 12);
+    }
+
+    #[test]
+    fn zero_rate_payment_is_principal_over_periods() {
+        let loan = Loan::new(1_200.0, 0.0, 12);
+        assert_eq!(loan.payment().unwrap(), 100.0);
+    }
+
+    #[test]
+    fn remaining_balance_before_any_payment_is_principal() {
+        let loan = Loan::new(5_000.0, 0.01, 10);
+        assert_eq!(loan.remaining_balance_after(0).unwrap(), 5_000.0);
+    }
+
+    #[test]
+    fn remaining_balance_after_all_payments_is_zero() {
+        let loan = Loan::new(5_000.0, 0.01, 10);
+        assert_eq!(loan.remaining_balance_after(10).unwrap(), 0.0);
+    }
+
+    #[test]
+    fn negative_principal_errors() {
+        let loan = Loan::new(-5_000.0, 0.01, 10);
+        assert_eq!(loan.payment().unwrap_err(), ValueError::OutOfRange);
+    }
+
+    #[test]
+    fn nan_principal_errors() {
+        let loan = Loan::new(f64::NAN, 0.01, 10);
+        assert_eq!(loan.payment().unwrap_err(), ValueError::OutOfRange);
+    }
+
+    #[test]
+    fn negative_rate_errors() {
+        let loan = Loan::new(5_000.0, -0.01, 10);
+        assert_eq!(loan.payment().unwrap_err(), ValueError::NegativeDiscount);
+    }
+
+    #[test]
+    fn zero_periods_errors_instead_of_dividing_by_zero() {
+        let loan = Loan::new(5_000.0, 0.01, 0);
+        assert_eq!(loan.payment().unwrap_err(), ValueError::EmptyCashFlow);
+
+        let zero_rate_loan = Loan::new(5_000.0, 0.0, 0);
+        assert_eq!(
+            zero_rate_loan.payment().unwrap_err(),
+            ValueError::EmptyCashFlow
+        );
+    }
+}
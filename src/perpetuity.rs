@@ -0,0 +1,203 @@
+//! Perpetuity and growing-annuity valuation: cash-flow series with no
+//! natural endpoint (or, for [`GrowingAnnuity`], a finite one) whose size
+//! is level ([`Perpetuity`]) or grows at a constant rate each period.
+//!
+//! These take [`crate::Annuity`]'s `Money<C>`/`Compounding` parameters
+//! rather than bare `f64` amounts, so a perpetuity's range checks and
+//! compounding convention stay consistent with every other valuation type
+//! in the crate. Only [`GrowingAnnuity`] also takes the `Regular`/`Due`
+//! marker; [`Perpetuity`] and [`GrowingPerpetuity`] value end-of-period
+//! payments only and have no `Due` form.
+
+use std::marker::PhantomData;
+
+use crate::{checked_amount, Compounding, Constraint, Due, Money, Regular, ValueError};
+
+/// A level cash flow received forever: PV = cashflow / rate.
+pub struct Perpetuity<C: Constraint> {
+    cashflow: Money<C>,
+    rate: f64,
+    compounding: Compounding,
+}
+
+impl<C: Constraint> Perpetuity<C> {
+    pub fn new(cashflow: Money<C>, rate: f64, compounding: Compounding) -> Self {
+        Self {
+            cashflow,
+            rate,
+            compounding,
+        }
+    }
+
+    pub fn present_value(&self) -> Result<f64, ValueError> {
+        let effective_rate = self.compounding.effective_rate(self.rate);
+        if effective_rate <= 0.0 {
+            return Err(ValueError::NegativeDiscount);
+        }
+        checked_amount::<C>(self.cashflow.minor_units() as f64 / effective_rate)
+    }
+}
+
+/// A cash flow growing at a constant rate `growth` forever:
+/// PV = cashflow / (rate - growth).
+pub struct GrowingPerpetuity<C: Constraint> {
+    cashflow: Money<C>,
+    rate: f64,
+    growth: f64,
+    compounding: Compounding,
+}
+
+impl<C: Constraint> GrowingPerpetuity<C> {
+    pub fn new(cashflow: Money<C>, rate: f64, growth: f64, compounding: Compounding) -> Self {
+        Self {
+            cashflow,
+            rate,
+            growth,
+            compounding,
+        }
+    }
+
+    pub fn present_value(&self) -> Result<f64, ValueError> {
+        let effective_rate = self.compounding.effective_rate(self.rate);
+        if effective_rate <= self.growth {
+            return Err(ValueError::RateBelowGrowth);
+        }
+        checked_amount::<C>(self.cashflow.minor_units() as f64 / (effective_rate - self.growth))
+    }
+}
+
+/// A finite annuity whose cash flow grows at a constant rate each period,
+/// marked `Regular` (end-of-period payments) or `Due` (start-of-period).
+pub struct GrowingAnnuity<C: Constraint, M> {
+    cashflow: Money<C>,
+    rate: f64,
+    growth: f64,
+    period: u32,
+    compounding: Compounding,
+    _marker: PhantomData<M>,
+}
+
+impl<C: Constraint, M> GrowingAnnuity<C, M> {
+    pub fn new(
+        cashflow: Money<C>,
+        rate: f64,
+        growth: f64,
+        period: u32,
+        compounding: Compounding,
+    ) -> Self {
+        Self {
+            cashflow,
+            rate,
+            growth,
+            period,
+            compounding,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<C: Constraint> GrowingAnnuity<C, Regular> {
+    /// PV = C/(r-g) * (1 - ((1+g)/(1+r))^n), where r is the compounding's
+    /// effective annual rate for this annuity's nominal `rate`.
+    pub fn present_value(&self) -> Result<f64, ValueError> {
+        let effective_rate = self.compounding.effective_rate(self.rate);
+        if effective_rate <= self.growth {
+            return Err(ValueError::RateBelowGrowth);
+        }
+
+        let ratio = (1.0 + self.growth) / (1.0 + effective_rate);
+        let pv = self.cashflow.minor_units() as f64 / (effective_rate - self.growth)
+            * (1.0 - ratio.powi(self.period as i32));
+        checked_amount::<C>(pv)
+    }
+}
+
+impl<C: Constraint> GrowingAnnuity<C, Due> {
+    pub fn present_value(&self) -> Result<f64, ValueError> {
+        let effective_rate = self.compounding.effective_rate(self.rate);
+        if effective_rate <= self.growth {
+            return Err(ValueError::RateBelowGrowth);
+        }
+
+        let regular: GrowingAnnuity<C, Regular> = GrowingAnnuity::new(
+            self.cashflow,
+            self.rate,
+            self.growth,
+            self.period,
+            self.compounding,
+        );
+        let pv = regular.present_value()? * (1.0 + effective_rate);
+        checked_amount::<C>(pv)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::NonNegative;
+
+    #[test]
+    fn perpetuity_pv() {
+        let p = Perpetuity::new(
+            Money::<NonNegative>::try_from_i64(1_000).unwrap(),
+            0.08,
+            Compounding::PerYear(1),
+        );
+        assert_eq!(p.present_value().unwrap(), 12_500.0);
+    }
+
+    #[test]
+    fn perpetuity_zero_rate_errors() {
+        let p = Perpetuity::new(
+            Money::<NonNegative>::try_from_i64(1_000).unwrap(),
+            0.0,
+            Compounding::PerYear(1),
+        );
+        assert_eq!(p.present_value().unwrap_err(), ValueError::NegativeDiscount);
+    }
+
+    #[test]
+    fn growing_perpetuity_pv() {
+        let p = GrowingPerpetuity::new(
+            Money::<NonNegative>::try_from_i64(1_000).unwrap(),
+            0.08,
+            0.03,
+            Compounding::PerYear(1),
+        );
+        assert_eq!(p.present_value().unwrap(), 20_000.0);
+    }
+
+    #[test]
+    fn growing_perpetuity_rate_below_growth_errors() {
+        let p = GrowingPerpetuity::new(
+            Money::<NonNegative>::try_from_i64(1_000).unwrap(),
+            0.03,
+            0.08,
+            Compounding::PerYear(1),
+        );
+        assert_eq!(
+            p.present_value().unwrap_err(),
+            ValueError::RateBelowGrowth
+        );
+    }
+
+    #[test]
+    fn growing_annuity_due_is_regular_times_one_plus_rate() {
+        let regular: GrowingAnnuity<NonNegative, Regular> = GrowingAnnuity::new(
+            Money::try_from_i64(1_000).unwrap(),
+            0.1,
+            0.05,
+            5,
+            Compounding::PerYear(1),
+        );
+        let due: GrowingAnnuity<NonNegative, Due> = GrowingAnnuity::new(
+            Money::try_from_i64(1_000).unwrap(),
+            0.1,
+            0.05,
+            5,
+            Compounding::PerYear(1),
+        );
+        let expected = crate::round(regular.present_value().unwrap() * 1.1);
+        assert_eq!(due.present_value().unwrap(), expected);
+    }
+}
@@ -0,0 +1,98 @@
+//! Compounding-frequency conventions and nominal/effective rate conversions.
+
+/// How often a nominal annual rate compounds.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Compounding {
+    /// Compounds `m` times per year (e.g. `PerYear(12)` for monthly).
+    PerYear(u32),
+    /// Compounds continuously.
+    Continuous,
+}
+
+impl Compounding {
+    /// The effective annual rate implied by a nominal annual `rate` under
+    /// this compounding convention.
+    pub fn effective_rate(&self, rate: f64) -> f64 {
+        match self {
+            Compounding::PerYear(m) => (1.0 + rate / *m as f64).powi(*m as i32) - 1.0,
+            Compounding::Continuous => rate.exp() - 1.0,
+        }
+    }
+
+    /// The growth factor (1 + r/m)^(m*t) for discrete compounding, or e^(r*t)
+    /// for continuous compounding, used to discount or accumulate a value
+    /// over `period` years at nominal annual `rate`.
+    pub(crate) fn growth_factor(&self, rate: f64, period: u32) -> f64 {
+        match self {
+            Compounding::PerYear(m) => (1.0 + rate / *m as f64).powf(*m as f64 * period as f64),
+            Compounding::Continuous => (rate * period as f64).exp(),
+        }
+    }
+}
+
+/// A nominal annual rate paired with its compounding convention, convertible
+/// to and from the equivalent effective annual rate.
+pub struct Rate {
+    nominal: f64,
+    compounding: Compounding,
+}
+
+impl Rate {
+    pub fn new(nominal: f64, compounding: Compounding) -> Self {
+        Self {
+            nominal,
+            compounding,
+        }
+    }
+
+    pub fn nominal(&self) -> f64 {
+        self.nominal
+    }
+
+    pub fn compounding(&self) -> Compounding {
+        self.compounding
+    }
+
+    /// EAR = (1 + nominal/m)^m - 1 for discrete compounding, or e^nominal - 1
+    /// for continuous compounding.
+    pub fn effective_annual_rate(&self) -> f64 {
+        self.compounding.effective_rate(self.nominal)
+    }
+
+    /// Recovers the nominal rate that produces `effective` under `compounding`.
+    pub fn from_effective_annual_rate(effective: f64, compounding: Compounding) -> Self {
+        let nominal = match compounding {
+            Compounding::PerYear(m) => m as f64 * ((1.0 + effective).powf(1.0 / m as f64) - 1.0),
+            Compounding::Continuous => (1.0 + effective).ln(),
+        };
+        Self {
+            nominal,
+            compounding,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn monthly_compounding_effective_rate() {
+        let rate = Rate::new(0.12, Compounding::PerYear(12));
+        assert!((rate.effective_annual_rate() - 0.126825).abs() < 1e-6);
+    }
+
+    #[test]
+    fn continuous_compounding_effective_rate() {
+        let rate = Rate::new(0.12, Compounding::Continuous);
+        assert!((rate.effective_annual_rate() - (0.12f64.exp() - 1.0)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn nominal_from_effective_round_trips() {
+        let original = Rate::new(0.12, Compounding::PerYear(12));
+        let ear = original.effective_annual_rate();
+        let recovered = Rate::from_effective_annual_rate(ear, Compounding::PerYear(12));
+        assert!((recovered.nominal() - 0.12).abs() < 1e-9);
+    }
+}
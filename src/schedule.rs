@@ -0,0 +1,130 @@
+//! `AsRows` flattens `Loan`, `CashFlowStream`, and `Annuity` into
+//! `(period, payment, interest, principal, balance)` tuples and provides a
+//! default `to_csv` writer on top of them; `to_dataframe`, behind the
+//! `polars` feature, builds a `polars::DataFrame` from the same rows.
+
+use std::io::{self, Write};
+
+use crate::cashflow::CashFlowStream;
+use crate::loan::Loan;
+use crate::{Annuity, Constraint};
+
+/// A `(period, payment, interest, principal, balance)` row. Types without a
+/// concept of interest/principal split (e.g. [`CashFlowStream`]) report the
+/// flow itself as `payment` and zero for the rest.
+pub type ScheduleRow = (u32, f64, f64, f64, f64);
+
+/// Flattens a schedule or cash-flow stream into [`ScheduleRow`]s.
+pub trait AsRows {
+    fn as_rows(&self) -> Vec<ScheduleRow>;
+
+    /// Writes `as_rows()` out as CSV with a `period,payment,interest,principal,balance` header.
+    fn to_csv<W: Write>(&self, mut w: W) -> io::Result<()> {
+        writeln!(w, "period,payment,interest,principal,balance")?;
+        for (period, payment, interest, principal, balance) in self.as_rows() {
+            writeln!(w, "{period},{payment},{interest},{principal},{balance}")?;
+        }
+        Ok(())
+    }
+}
+
+impl AsRows for Loan {
+    /// Empty if the loan's principal, rate or period count is invalid; see
+    /// [`Loan::schedule`] for the fallible form that reports why.
+    fn as_rows(&self) -> Vec<ScheduleRow> {
+        self.schedule()
+            .unwrap_or_default()
+            .into_iter()
+            .map(|row| {
+                (
+                    row.period,
+                    row.payment,
+                    row.interest,
+                    row.principal,
+                    row.balance,
+                )
+            })
+            .collect()
+    }
+}
+
+impl AsRows for CashFlowStream {
+    fn as_rows(&self) -> Vec<ScheduleRow> {
+        self.flows()
+            .iter()
+            .enumerate()
+            .map(|(t, cf)| (t as u32, *cf, 0.0, 0.0, 0.0))
+            .collect()
+    }
+}
+
+impl<C, M> AsRows for Annuity<C, M>
+where
+    C: Constraint,
+{
+    /// `payment` is the raw `Money` amount in whatever unit the `Annuity`
+    /// was constructed with, the same unit `Loan`'s `f64` amounts use.
+    fn as_rows(&self) -> Vec<ScheduleRow> {
+        let cashflow = self.cashflow().minor_units() as f64;
+        (1..=self.period())
+            .map(|period| (period, cashflow, 0.0, 0.0, 0.0))
+            .collect()
+    }
+}
+
+/// Builds a [`polars::prelude::DataFrame`] from schedule rows, for users who
+/// want to hand the output straight to polars instead of CSV.
+#[cfg(feature = "polars")]
+pub fn to_dataframe(rows: &[ScheduleRow]) -> polars::prelude::PolarsResult<polars::prelude::DataFrame> {
+    use polars::prelude::*;
+
+    df! {
+        "period" => rows.iter().map(|r| r.0).collect::<Vec<_>>(),
+        "payment" => rows.iter().map(|r| r.1).collect::<Vec<_>>(),
+        "interest" => rows.iter().map(|r| r.2).collect::<Vec<_>>(),
+        "principal" => rows.iter().map(|r| r.3).collect::<Vec<_>>(),
+        "balance" => rows.iter().map(|r| r.4).collect::<Vec<_>>(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Compounding, Money, NonNegative, Regular};
+
+    #[test]
+    fn loan_to_csv_has_one_data_row_per_period() {
+        let loan = Loan::new(1_000.0, 0.01, 3);
+        let mut out = Vec::new();
+        loan.to_csv(&mut out).unwrap();
+        let text = String::from_utf8(out).unwrap();
+        assert_eq!(text.lines().count(), 4);
+    }
+
+    #[test]
+    fn cash_flow_stream_as_rows_mirrors_flows() {
+        let stream = CashFlowStream::new(vec![-100.0, 50.0, 60.0]);
+        let rows = stream.as_rows();
+        assert_eq!(rows, vec![(0, -100.0, 0.0, 0.0, 0.0), (1, 50.0, 0.0, 0.0, 0.0), (2, 60.0, 0.0, 0.0, 0.0)]);
+    }
+
+    #[test]
+    fn annuity_as_rows_has_one_row_per_period() {
+        let annuity: Annuity<NonNegative, Regular> = Annuity::new(
+            Money::try_from_i64(500).unwrap(),
+            0.05,
+            4,
+            Compounding::PerYear(1),
+        );
+        assert_eq!(annuity.as_rows().len(), 4);
+    }
+
+    #[cfg(feature = "polars")]
+    #[test]
+    fn to_dataframe_has_one_row_per_schedule_row() {
+        let loan = Loan::new(1_000.0, 0.01, 3);
+        let df = to_dataframe(&loan.as_rows()).unwrap();
+        assert_eq!(df.height(), 3);
+        assert_eq!(df.width(), 5);
+    }
+}